@@ -1,25 +1,27 @@
 use super::*;
 
-use polynomial_ops::*;
-use array__ops::*;
-
 #[const_trait]
 pub trait ApproxCos
 {
-    /// Calculates an approximation of a cosine, using the Z80 / ZX Spectrum algorithm.
-    /// 
-    /// https://namoseley.wordpress.com/2012/09/26/arduinoavr-and-zx-spectrum-sin-routines/
-    /// 
+    /// Calculates an approximation of a cosine.
+    ///
+    /// This reduces the argument to the nearest half-integer multiple of π and evaluates a
+    /// Chebyshev kernel on the remainder, see [`ApproxSinCos::approx_sin_cos`]. The argument is
+    /// scaled by 1/π with one rounded multiplication, so the absolute error grows with `|x|`.
+    ///
     /// This approximation is significantly slower than the built-in cos method in the standard library,
     /// however this method can be run at compile-time.
-    /// 
+    ///
+    /// If both sine and cosine are needed, prefer [`ApproxSinCos::approx_sin_cos`], which shares
+    /// the range reduction between the two.
+    ///
     /// # Example
-    /// 
+    ///
     /// ```rust
     /// #![feature(const_trait_impl)]
-    /// 
+    ///
     /// use float_approx_math::ApproxCos;
-    /// 
+    ///
     /// const X: f32 = 2.0;
     /// let y: f32 = X.approx_cos();
     ///
@@ -34,46 +36,17 @@ macro_rules! impl_approx_cos {
         {
             fn approx_cos(self) -> Self
             {
-                const N: usize = 6;
-                const C: [$float; N] = [
-                    1.276278962,
-                    -0.285261569,
-                    0.009118016,
-                    -0.000136587,
-                    0.000001185,
-                    -0.000000007
-                ];
-                let t: [[$float; N]; N] = ArrayOps::fill(
-                    /*const*/ |n| Into::<Option<[$float; N]>>::into(ChebyshevPolynomial::new_of_first_kind(n)).unwrap()
-                );
-                let p: [$float; N] = t.zip(C)
-                    .map2(/*const*/ |(t, c)| t.map2(const |tn| c*tn))
-                    .reduce(/*const*/ |a, b| a.zip(b).map2(const |(a, b)| a + b))
-                    .unwrap_or_default();
-
-                let mut w = self*$consts::FRAC_2_PI + 1.0;
-                let mut i = 0;
-                while i < 4
-                {
-                    w = (w - 1.0);
-                    if i % 2 == 0 && w < 0.0
-                    {
-                        w = -w;
-                    }
-                    w %= 4.0;
-                    i += 1;
-                }
-                let w = if w > 1.0 {2.0 - w} else if w < -1.0 {-2.0 - w} else {w};
-
-                let z = 2.0*w*w - 1.0;
-
-                p.evaluate_as_polynomial(z)*w
+                self.approx_sin_cos().1
             }
         }
     };
 }
 impl_approx_cos!(f32; f32);
 impl_approx_cos!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_cos!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_cos!(f128; f128);
 
 #[cfg(test)]
 mod test
@@ -0,0 +1,80 @@
+use super::*;
+
+#[const_trait]
+pub trait ApproxCosh
+{
+    /// Calculates an approximation of `cosh(x)`, as `(e^x + e^-x)/2` using [`ApproxExp::approx_exp`].
+    ///
+    /// Unlike [`ApproxSinh::approx_sinh`], this sum never suffers cancellation, so no small-`x`
+    /// fallback is needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxCosh;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_cosh::<8>();
+    ///
+    /// assert!((y - x.cosh()).abs() < 0.0001);
+    /// ```
+    fn approx_cosh<const N: usize>(self) -> Self;
+}
+
+macro_rules! impl_approx_cosh {
+    ($float:ty; $consts:tt) => {
+        impl /*const*/ ApproxCosh for $float
+        {
+            fn approx_cosh<const N: usize>(self) -> Self
+            {
+                (self.approx_exp::<N>() + (-self).approx_exp::<N>())*0.5
+            }
+        }
+    };
+}
+impl_approx_cosh!(f32; f32);
+impl_approx_cosh!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_cosh!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_cosh!(f128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn cosh()
+    {
+        const X: f64 = 2.0;
+        let y = X.approx_cosh::<8>();
+
+        println!("{}", X.cosh());
+        println!("{}", y);
+        println!("error = {}", (y - X.cosh())/X.cosh());
+    }
+
+    #[bench]
+    fn cosh_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "cosh",
+            [
+                &F::cosh,
+                &ApproxCosh::approx_cosh::<4>,
+                &ApproxCosh::approx_cosh::<8>
+            ],
+            -4.0..4.0,
+            S
+        )
+    }
+}
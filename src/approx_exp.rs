@@ -0,0 +1,215 @@
+use super::*;
+
+use polynomial_ops::*;
+
+#[const_trait]
+pub trait ApproxExp
+{
+    /// Calculates an approximation of `e^x`.
+    ///
+    /// Internally this is `approx_exp2::<N>(x * LOG2_E)`, see [`ApproxExp::approx_exp2`] for the
+    /// details of the approximation and the meaning of `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxExp;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_exp::<8>();
+    ///
+    /// assert!((y - x.exp()).abs() < 0.0001);
+    /// ```
+    fn approx_exp<const N: usize>(self) -> Self;
+
+    /// Calculates an approximation of `2^x`, using Schraudolph's bit-hack.
+    ///
+    /// The integer part `n = floor(x)` is written directly into the exponent field of the
+    /// result, and the fractional part `f = x - n` is corrected by an `N`-term Chebyshev-economized
+    /// minimax polynomial for `2^f`, `f` in `[0, 1)`, baked per-degree instead of a Maclaurin
+    /// series (whose error is much worse at low `N`). Larger `N` trades speed for accuracy, up to
+    /// the 9 baked degrees; beyond that the extra terms are `0.0`. Inputs that overflow saturate
+    /// to `INFINITY`; inputs whose result would be subnormal or smaller saturate to `0.0`, since
+    /// this bit-hack cannot place a subnormal exponent field. `NAN` propagates as `NAN`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxExp;
+    ///
+    /// let x: f32 = 3.0;
+    /// let y: f32 = x.approx_exp2::<8>();
+    ///
+    /// assert!((y - x.exp2()).abs() < 0.0001);
+    /// ```
+    fn approx_exp2<const N: usize>(self) -> Self;
+
+    /// Calculates an approximation of `10^x`.
+    ///
+    /// Internally this is `approx_exp2::<N>(x * LOG2_10)`, see [`ApproxExp::approx_exp2`] for the
+    /// details of the approximation and the meaning of `N`.
+    fn approx_exp10<const N: usize>(self) -> Self;
+}
+
+macro_rules! impl_approx_exp {
+    ($float:ty: $bits:ty; $consts:tt) => {
+        // Not `const`: `evaluate_as_polynomial` isn't a const fn yet, same reason
+        // `approx_sin_cos`/`approx_log` aren't `impl const` either.
+        impl /*const*/ ApproxExp for $float
+        {
+            fn approx_exp<const N: usize>(self) -> Self
+            {
+                (self*$consts::LOG2_E).approx_exp2::<N>()
+            }
+            fn approx_exp2<const N: usize>(self) -> Self
+            {
+                if self.is_nan()
+                {
+                    return <$float>::NAN;
+                }
+                if self >= <$float>::MAX_EXP as $float
+                {
+                    return <$float>::INFINITY;
+                }
+
+                let n = self.floor();
+
+                // A biased exponent field of EXP_BIAS + n <= 0 can't be represented by this
+                // bit-hack (it would wrap to the infinity/NaN pattern instead of a subnormal),
+                // so the whole subnormal-result band saturates to 0.0.
+                if $consts::EXP_BIAS as i64 + n as i64 <= 0
+                {
+                    return 0.0;
+                }
+
+                let f = self - n;
+
+                // Chebyshev-economized minimax coefficients for 2^f, f in [0, 1), baked
+                // per-degree (row k approximates with k + 1 terms) rather than recomputing a
+                // Maclaurin series every call, which is both slower and far less accurate at
+                // low N.
+                const MAX_N: usize = 9;
+                const EXP2_COEFFS: [[$float; MAX_N]; MAX_N] = [
+                    [1.456999875012963, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [0.9594750072025213, 0.9950497356208833, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [1.002368119192201, 0.6519048397034464, 0.34314489591743685, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [0.9998966910282048, 0.6963905466553763, 0.22451634404562426, 0.07908570124787506, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [1.0000035971445629, 0.6929695509319193, 0.2416213226629087, 0.05171773546021996, 0.013683982893827551, 0.0, 0.0, 0.0, 0.0],
+                    [0.9999998957631353, 0.6931546200033009, 0.24014077009185636, 0.05586328265916651, 0.008946214666460062, 0.0018951072909469957, 0.0, 0.0, 0.0],
+                    [1.000000002586889, 0.6931469286930282, 0.24023050204503754, 0.05548042632559347, 0.009684580452636649, 0.0012387821476789185, 0.00021877504775602574, 0.0, 0.0],
+                    [0.999999999943856, 0.6931471877102445, 0.24022635776957518, 0.05550529197836762, 0.009613535730424783, 0.0013429810735896552, 0.00014299401073003537, 2.1651724864568678e-05, 0.0],
+                    [1.0000000000010825, 0.6931471803852617, 0.24022651159421488, 0.05550406138125008, 0.009618370219100836, 0.0013326674977474084, 0.0001551827821799634, 1.4150942433843738e-05, 1.8751956076812348e-06]
+                ];
+
+                let row = &EXP2_COEFFS[if N == 0 { 0 } else { (N - 1).min(MAX_N - 1) }];
+
+                // `N == 0` is a degenerate, zero-length `coeffs`, which would make
+                // `evaluate_as_polynomial` return 0.0 and zero out the whole result. Row 0 still
+                // holds a meaningful single-term constant for that case, so use it directly.
+                let poly = if N == 0
+                {
+                    row[0]
+                }
+                else
+                {
+                    let mut coeffs: [$float; N] = [0.0; N];
+                    let mut k = 0;
+                    while k < N
+                    {
+                        coeffs[k] = if k < MAX_N { row[k] } else { 0.0 };
+                        k += 1;
+                    }
+                    coeffs.evaluate_as_polynomial(f)
+                };
+
+                let bits = (($consts::EXP_BIAS as i64 + n as i64) as $bits) << (<$float>::MANTISSA_DIGITS as $bits - 1);
+
+                <$float>::from_bits(bits)*poly
+            }
+            fn approx_exp10<const N: usize>(self) -> Self
+            {
+                (self*$consts::LOG2_10).approx_exp2::<N>()
+            }
+        }
+    };
+}
+impl_approx_exp!(f32: u32; f32);
+impl_approx_exp!(f64: u64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_exp!(f16: u16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_exp!(f128: u128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn exp()
+    {
+        const RANGE: f32 = 80.0;
+        t::plot_approx("exp", -RANGE..RANGE, f32::exp, ApproxExp::approx_exp::<8>)
+    }
+
+    #[test]
+    fn exp2()
+    {
+        // Covers the overflow tail, the subnormal-result underflow band, and ordinary values.
+        const RANGE: f32 = 150.0;
+        t::plot_approx("exp2", -RANGE..RANGE, f32::exp2, ApproxExp::approx_exp2::<8>)
+    }
+
+    #[test]
+    fn exp10()
+    {
+        const RANGE: f32 = 35.0;
+        t::plot_approx("exp10", -RANGE..RANGE, |x| 10f32.powf(x), ApproxExp::approx_exp10::<8>)
+    }
+
+    #[test]
+    fn exp2_subnormal_result_saturates_to_zero()
+    {
+        // Regression test: `self` lands below the smallest representable exponent field
+        // (EXP_BIAS + floor(self) <= 0), which must saturate to 0.0 instead of wrapping the
+        // exponent field into the infinity/NaN bit pattern.
+        let y: f32 = (-128.5f32).approx_exp2::<8>();
+
+        assert_eq!(y, 0.0);
+    }
+
+    #[test]
+    fn exp2_nan_propagates()
+    {
+        // Regression test: at low `N` (in particular `N <= 1`) the correction polynomial never
+        // touches `f`, so without an explicit guard `NaN` would fall through to the bit-hack and
+        // come out as a finite number instead of propagating.
+        let y: f32 = f32::NAN.approx_exp2::<1>();
+
+        assert!(y.is_nan());
+    }
+
+    #[bench]
+    fn exp2_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "exp2",
+            [
+                &F::exp2,
+                &ApproxExp::approx_exp2::<2>,
+                &ApproxExp::approx_exp2::<4>,
+                &ApproxExp::approx_exp2::<8>
+            ],
+            -10.0..10.0,
+            S
+        )
+    }
+}
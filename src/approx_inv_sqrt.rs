@@ -1,5 +1,18 @@
 use super::*;
 
+/// σ tuning the classic Quake III magic number `0x5f3759df`.
+///
+/// For `f32` its maximum relative error is about 3.44% with no Newton-Raphson iteration and
+/// about 0.175% after one. This is the σ used by [`ApproxInvSqrt::approx_inv_sqrt`] by default.
+pub const SIGMA_QUAKE: f64 = 0.0450466;
+
+/// σ tuning Chris Lomont's refined magic number `0x5f375a86`.
+///
+/// Minimizes the maximum relative error *after one* Newton-Raphson iteration. For `f32` its
+/// maximum relative error is marginally below that of [`SIGMA_QUAKE`] both with no iteration
+/// and after one (about 3.437% against 3.438%, and 0.1751% against 0.1752%).
+pub const SIGMA_LOMONT: f64 = 0.0450333;
+
 #[const_trait]
 pub trait ApproxInvSqrt
 {
@@ -28,6 +41,35 @@ pub trait ApproxInvSqrt
     fn approx_inv_sqrt_unchecked<const NEWTON: usize>(self) -> Self;
 }
 
+/// Tunable variants of [`ApproxInvSqrt`] that take the magic-number constant σ as a parameter
+/// instead of hardcoding [`SIGMA_QUAKE`], and use a fused multiply-add in the Newton-Raphson
+/// step.
+///
+/// The fma form `y * (x2*y).mul_add(-y, 1.5)` rounds the product-then-subtract in a single step
+/// on targets with hardware fma, which is why these entry points are not `const`: `mul_add` is
+/// not a const fn. Reach for [`ApproxInvSqrt`] instead when a compile-time result is needed and
+/// the default tuning is good enough.
+pub trait ApproxInvSqrtTunable
+{
+    /// Calculates an approximation of 1/sqrt(x) using a caller-chosen σ, e.g. [`SIGMA_QUAKE`] or
+    /// [`SIGMA_LOMONT`], or a custom constant minimizing max relative error for a known input
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::{ApproxInvSqrtTunable, SIGMA_LOMONT};
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_inv_sqrt_with::<1>(SIGMA_LOMONT as f32);
+    ///
+    /// assert!((y - x.sqrt().recip()).abs() < 0.001);
+    /// ```
+    fn approx_inv_sqrt_with<const NEWTON: usize>(self, sigma: Self) -> Self;
+
+    fn approx_inv_sqrt_unchecked_with<const NEWTON: usize>(self, sigma: Self) -> Self;
+}
+
 macro_rules! impl_approx_inv_sqrt {
     ($float:ty: $bits:ty; $consts:tt) => {
         impl const ApproxInvSqrt for $float
@@ -46,8 +88,7 @@ macro_rules! impl_approx_inv_sqrt {
             fn approx_inv_sqrt_unchecked<const NEWTON: usize>(self) -> Self
             {
                 const L: $bits = 1 << (<$float>::MANTISSA_DIGITS as $bits - 1);
-                const SIGMA: f64 = 0.0450466;
-                const MAGIC_NUMBER: $bits = (1.5*L as f64*($consts::EXP_BIAS as f64 - SIGMA) + 0.5) as $bits;
+                const MAGIC_NUMBER: $bits = (1.5*L as f64*($consts::EXP_BIAS as f64 - SIGMA_QUAKE) + 0.5) as $bits;
 
                 let mut y = <$float>::from_bits(MAGIC_NUMBER - (<$float>::to_bits(self) >> 1));
 
@@ -61,6 +102,37 @@ macro_rules! impl_approx_inv_sqrt {
                 y
             }
         }
+
+        impl ApproxInvSqrtTunable for $float
+        {
+            fn approx_inv_sqrt_with<const NEWTON: usize>(self, sigma: Self) -> Self
+            {
+                if self > 0.0
+                {
+                    self.approx_inv_sqrt_unchecked_with::<NEWTON>(sigma)
+                }
+                else
+                {
+                    <$float>::NAN
+                }
+            }
+            fn approx_inv_sqrt_unchecked_with<const NEWTON: usize>(self, sigma: Self) -> Self
+            {
+                let l: $bits = 1 << (<$float>::MANTISSA_DIGITS as $bits - 1);
+                let magic_number: $bits = (1.5*l as f64*($consts::EXP_BIAS as f64 - sigma as f64) + 0.5) as $bits;
+
+                let mut y = <$float>::from_bits(magic_number - (<$float>::to_bits(self) >> 1));
+
+                let x2 = self*0.5;
+                let mut i = 0;
+                while i < NEWTON
+                {
+                    y *= (x2*y).mul_add(-y, 1.5);
+                    i += 1;
+                }
+                y
+            }
+        }
     };
 }
 
@@ -76,12 +148,51 @@ mod test
     fn verify_magic_number_f32()
     {
         const L: u32 = 1 << (<f32>::MANTISSA_DIGITS - 1);
-        const SIGMA: f64 = 0.0450466;
-        const MAGIC_NUMBER: u32 = (1.5*L as f64*(f32::EXP_BIAS as f64 - SIGMA) + 0.5) as u32;
-    
+        const MAGIC_NUMBER: u32 = (1.5*L as f64*(f32::EXP_BIAS as f64 - SIGMA_QUAKE) + 0.5) as u32;
+
         assert_eq!(MAGIC_NUMBER, 0x5f3759df);
     }
-    
+
+    #[test]
+    fn verify_magic_number_lomont_f32()
+    {
+        const L: u32 = 1 << (<f32>::MANTISSA_DIGITS - 1);
+        const MAGIC_NUMBER: u32 = (1.5*L as f64*(f32::EXP_BIAS as f64 - SIGMA_LOMONT) + 0.5) as u32;
+
+        assert_eq!(MAGIC_NUMBER, 0x5f375a86);
+    }
+
+    #[cfg(feature = "unstable_float_types")]
+    #[test]
+    fn verify_magic_number_f16()
+    {
+        const L: u16 = 1 << (<f16>::MANTISSA_DIGITS - 1);
+        const MAGIC_NUMBER: u16 = (1.5*L as f64*(f16::EXP_BIAS as f64 - SIGMA_QUAKE) + 0.5) as u16;
+
+        assert_eq!(MAGIC_NUMBER, 0x59bb);
+    }
+
+    #[cfg(feature = "unstable_float_types")]
+    #[test]
+    fn verify_magic_number_f128()
+    {
+        const L: u128 = 1 << (<f128>::MANTISSA_DIGITS - 1);
+        const MAGIC_NUMBER: u128 = (1.5*L as f64*(f128::EXP_BIAS as f64 - SIGMA_QUAKE) + 0.5) as u128;
+
+        assert_eq!(MAGIC_NUMBER, 0x5ffe6eb3bd3150000000000000000000);
+    }
+
+    #[test]
+    fn inv_sqrt_with()
+    {
+        const X: f32 = 2.0;
+        let y = X.approx_inv_sqrt_with::<1>(SIGMA_LOMONT as f32);
+
+        println!("{}", X.sqrt().recip());
+        println!("{}", y);
+        println!("error = {}", (y - X.sqrt().recip())/X.sqrt().recip());
+    }
+
     #[test]
     fn inv_sqrt()
     {
@@ -131,4 +242,8 @@ mod test
 }
 
 impl_approx_inv_sqrt!(f32: u32; f32);
-impl_approx_inv_sqrt!(f64: u64; f64);
\ No newline at end of file
+impl_approx_inv_sqrt!(f64: u64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_inv_sqrt!(f16: u16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_inv_sqrt!(f128: u128; f128);
\ No newline at end of file
@@ -0,0 +1,216 @@
+use super::*;
+
+use polynomial_ops::*;
+
+#[const_trait]
+pub trait ApproxLog
+{
+    /// Calculates an approximation of `ln(x)`.
+    ///
+    /// Internally this is `approx_log2::<N>(x) * LN_2`, see [`ApproxLog::approx_log2`] for the
+    /// details of the approximation and the meaning of `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxLog;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_ln::<8>();
+    ///
+    /// assert!((y - x.ln()).abs() < 0.0001);
+    /// ```
+    fn approx_ln<const N: usize>(self) -> Self;
+
+    /// Calculates an approximation of `log2(x)`, as the inverse of the bit-hack used by
+    /// [`ApproxExp::approx_exp2`].
+    ///
+    /// The exponent field of the input's bit pattern directly gives the integer part `e` of the
+    /// result, and the mantissa, normalized into `m` in `[1, 2)`, is corrected by an `N`-term
+    /// series for `log2(m)`. Larger `N` trades speed for accuracy. Returns `NEG_INFINITY` for
+    /// `0.0`, and `NAN` for negative inputs and `NAN` inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxLog;
+    ///
+    /// let x: f32 = 3.0;
+    /// let y: f32 = x.approx_log2::<8>();
+    ///
+    /// assert!((y - x.log2()).abs() < 0.0001);
+    /// ```
+    fn approx_log2<const N: usize>(self) -> Self;
+
+    /// Calculates an approximation of `log10(x)`.
+    ///
+    /// Internally this is `approx_log2::<N>(x) * LOG10_2`, see [`ApproxLog::approx_log2`] for the
+    /// details of the approximation and the meaning of `N`.
+    fn approx_log10<const N: usize>(self) -> Self;
+}
+
+macro_rules! impl_approx_log {
+    ($float:ty: $bits:ty; $consts:tt) => {
+        impl /*const*/ ApproxLog for $float
+        {
+            fn approx_ln<const N: usize>(self) -> Self
+            {
+                self.approx_log2::<N>()*$consts::LN_2
+            }
+            fn approx_log2<const N: usize>(self) -> Self
+            {
+                if self.is_nan()
+                {
+                    return <$float>::NAN;
+                }
+                if self == 0.0
+                {
+                    return <$float>::NEG_INFINITY;
+                }
+                if self < 0.0
+                {
+                    return <$float>::NAN;
+                }
+
+                const MANTISSA_BITS: u32 = <$float>::MANTISSA_DIGITS - 1;
+                const EXP_BITS: u32 = <$bits>::BITS - <$float>::MANTISSA_DIGITS;
+                const MANTISSA_MASK: $bits = (1 << MANTISSA_BITS) - 1;
+                const EXP_MASK: $bits = (1 << EXP_BITS) - 1;
+                const ONE_EXPONENT: $bits = ($consts::EXP_BIAS as $bits) << MANTISSA_BITS;
+
+                let bits = <$float>::to_bits(self);
+                let e = (((bits >> MANTISSA_BITS) & EXP_MASK) as i64) - ($consts::EXP_BIAS as i64);
+                let m = <$float>::from_bits((bits & MANTISSA_MASK) | ONE_EXPONENT);
+
+                // ln(m) = 2*s*atanh(s)/s, s = (m - 1)/(m + 1), m in [1, 2) so s in [0, 1/3).
+                // atanh(s)/s is evaluated as an N-term polynomial of u = s*s, using
+                // Chebyshev-economized minimax coefficients baked per-degree (row k
+                // approximates with k + 1 terms) instead of recomputing the Taylor series
+                // 1/(2k + 1) every call.
+                let s = (m - 1.0)/(m + 1.0);
+                let u = s*s;
+
+                const MAX_N: usize = 9;
+                const ATANH_OVER_S_COEFFS: [[$float; MAX_N]; MAX_N] = [
+                    [1.019510728362366, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [0.9996576820413788, 0.35735483377776656, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [1.000007171253778, 0.3321916104850274, 0.2264690096346526, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [0.9999998361942397, 0.33337989013023406, 0.19795029814969378, 0.17111226890975295, 0.0, 0.0, 0.0, 0.0, 0.0],
+                    [1.0000000039388746, 0.3333315796754055, 0.20012426861697896, 0.13980709418084636, 0.14087328628007967, 0.0, 0.0, 0.0, 0.0],
+                    [0.9999999999020054, 0.3333333962664612, 0.19999347406096812, 0.14310311699231948, 0.10697133736207043, 0.12204701610483326, 0.0, 0.0, 0.0],
+                    [1.000000000002498, 0.33333333114731234, 0.20000031157159456, 0.1428405565842642, 0.11152863587331563, 0.08558862801487166, 0.1093751642698848, 0.0, 0.0],
+                    [0.9999999999999354, 0.3333333334076045, 0.19999998608950828, 0.14285813261692348, 0.1110766807477912, 0.09155443567179411, 0.07032624142457422, 0.1004115158879415, 0.0],
+                    [1.0000000000000016, 0.33333333333104975, 0.2000000005583584, 0.14285709085971499, 0.1111135143062345, 0.09084723134968264, 0.07784832375975981, 0.05875075218537518, 0.09373671833077424]
+                ];
+
+                let row = &ATANH_OVER_S_COEFFS[if N == 0 { 0 } else { (N - 1).min(MAX_N - 1) }];
+
+                // `N == 0` is a degenerate, zero-length `coeffs`, which would make
+                // `evaluate_as_polynomial` return 0.0 and discard the whole mantissa correction.
+                // Row 0 still holds a meaningful single-term constant for that case, so use it
+                // directly.
+                let atanh_over_s = if N == 0
+                {
+                    row[0]
+                }
+                else
+                {
+                    let mut coeffs: [$float; N] = [0.0; N];
+                    let mut k = 0;
+                    while k < N
+                    {
+                        coeffs[k] = if k < MAX_N { row[k] } else { 0.0 };
+                        k += 1;
+                    }
+                    coeffs.evaluate_as_polynomial(u)
+                };
+                let ln_m = 2.0*s*atanh_over_s;
+
+                e as $float + ln_m*$consts::LOG2_E
+            }
+            fn approx_log10<const N: usize>(self) -> Self
+            {
+                self.approx_log2::<N>()*$consts::LOG10_2
+            }
+        }
+    };
+}
+impl_approx_log!(f32: u32; f32);
+impl_approx_log!(f64: u64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_log!(f16: u16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_log!(f128: u128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn ln()
+    {
+        const X: f64 = 3.0;
+        let y = X.approx_ln::<8>();
+
+        println!("{}", X.ln());
+        println!("{}", y);
+        println!("error = {}", (y - X.ln())/X.ln());
+    }
+
+    #[test]
+    fn log2()
+    {
+        const X: f64 = 3.0;
+        let y = X.approx_log2::<8>();
+
+        println!("{}", X.log2());
+        println!("{}", y);
+        println!("error = {}", (y - X.log2())/X.log2());
+    }
+
+    #[test]
+    fn log10()
+    {
+        const X: f64 = 3.0;
+        let y = X.approx_log10::<8>();
+
+        println!("{}", X.log10());
+        println!("{}", y);
+        println!("error = {}", (y - X.log10())/X.log10());
+    }
+
+    #[test]
+    fn log2_nan_propagates()
+    {
+        // Regression test: `NaN` must not fall through to the bit-extraction path, which would
+        // reinterpret its mantissa payload as a normal `[1, 2)` value and return a finite result.
+        let y: f32 = f32::NAN.approx_log2::<8>();
+
+        assert!(y.is_nan());
+    }
+
+    #[bench]
+    fn log2_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "log2",
+            [
+                &F::log2,
+                &ApproxLog::approx_log2::<2>,
+                &ApproxLog::approx_log2::<4>,
+                &ApproxLog::approx_log2::<8>
+            ],
+            0.1..256.0,
+            S
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+#[const_trait]
+pub trait ApproxSigmoid
+{
+    /// Calculates an approximation of the logistic sigmoid `1/(1 + e^-x)`.
+    ///
+    /// This is computed as `0.5*(1 + tanh(x/2))` using [`ApproxTanh::approx_tanh`], reusing the
+    /// same exponential path instead of evaluating a second, independent exponential.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxSigmoid;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_sigmoid::<8>();
+    ///
+    /// assert!((y - 1.0/(1.0 + (-x).exp())).abs() < 0.0001);
+    /// ```
+    fn approx_sigmoid<const N: usize>(self) -> Self;
+}
+
+macro_rules! impl_approx_sigmoid {
+    ($float:ty; $consts:tt) => {
+        impl /*const*/ ApproxSigmoid for $float
+        {
+            fn approx_sigmoid<const N: usize>(self) -> Self
+            {
+                0.5*(1.0 + (self*0.5).approx_tanh::<N>())
+            }
+        }
+    };
+}
+impl_approx_sigmoid!(f32; f32);
+impl_approx_sigmoid!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sigmoid!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sigmoid!(f128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn sigmoid()
+    {
+        const X: f64 = 2.0;
+        let y = X.approx_sigmoid::<8>();
+        let reference = 1.0/(1.0 + (-X).exp());
+
+        println!("{}", reference);
+        println!("{}", y);
+        println!("error = {}", (y - reference)/reference);
+    }
+
+    #[bench]
+    fn sigmoid_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "sigmoid",
+            [
+                &|x: F| 1.0/(1.0 + (-x).exp()),
+                &ApproxSigmoid::approx_sigmoid::<4>,
+                &ApproxSigmoid::approx_sigmoid::<8>
+            ],
+            -4.0..4.0,
+            S
+        )
+    }
+}
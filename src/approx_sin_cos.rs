@@ -0,0 +1,139 @@
+use super::*;
+
+use polynomial_ops::*;
+use array__ops::*;
+
+#[const_trait]
+pub trait ApproxSinCos
+{
+    /// Calculates an approximation of both `sin(x)` and `cos(x)` at once, sharing a single
+    /// range reduction between the two.
+    ///
+    /// The argument is reduced to the nearest half-integer multiple of π (`xi = round(x/π*2)`),
+    /// which keeps the reduced remainder within `[-π/4, π/4]` no matter how large `x` is. The
+    /// scaling `x/π` is one rounded multiplication, so the absolute error still grows with `|x|`
+    /// (for `f32`, about 2.4e-4 at `x = 1000*τ`). Since both outputs
+    /// share that one reduction, calling this is cheaper than calling [`ApproxSin::approx_sin`]
+    /// and [`ApproxCos::approx_cos`] separately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_trait_impl)]
+    ///
+    /// use float_approx_math::ApproxSinCos;
+    ///
+    /// const X: f32 = 2.0;
+    /// let (s, c): (f32, f32) = X.approx_sin_cos();
+    ///
+    /// assert!((s - X.sin()).abs() < 0.0000005); // Less than 0.0000005 abs error
+    /// assert!((c - X.cos()).abs() < 0.0000005); // Less than 0.0000005 abs error
+    /// ```
+    fn approx_sin_cos(self) -> (Self, Self)
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_approx_sin_cos {
+    ($float:ty; $consts:tt) => {
+        impl /*const*/ ApproxSinCos for $float
+        {
+            fn approx_sin_cos(self) -> (Self, Self)
+            {
+                const N: usize = 6;
+                // Chebyshev series of sin(pi*xk)/xk, as a function of z = 32*xk*xk - 1, xk in [-1/4, 1/4]
+                const CS: [$float; N] = [
+                    2.983791842,
+                    -0.156578270,
+                    0.001218038,
+                    -0.000004494,
+                    0.000000010,
+                    -0.000000000
+                ];
+                // Chebyshev series of cos(pi*xk), as a function of z = 32*xk*xk - 1, xk in [-1/4, 1/4]
+                const CC: [$float; N] = [
+                    0.851631914,
+                    -0.146436644,
+                    0.001921449,
+                    -0.000009965,
+                    0.000000028,
+                    -0.000000000
+                ];
+                let t: [[$float; N]; N] = ArrayOps::fill(
+                    /*const*/ |n| Into::<Option<[$float; N]>>::into(ChebyshevPolynomial::new_of_first_kind(n)).unwrap()
+                );
+                let ps: [$float; N] = t.zip(CS)
+                    .map2(/*const*/ |(t, c)| t.map2(const |tn| c*tn))
+                    .reduce(/*const*/ |a, b| a.zip(b).map2(const |(a, b)| a + b))
+                    .unwrap_or_default();
+                let pc: [$float; N] = t.zip(CC)
+                    .map2(/*const*/ |(t, c)| t.map2(const |tn| c*tn))
+                    .reduce(/*const*/ |a, b| a.zip(b).map2(const |(a, b)| a + b))
+                    .unwrap_or_default();
+
+                let x = self*$consts::FRAC_1_PI;
+                let xi = (x*2.0).round();
+                let xk = x - xi*0.5;
+
+                let z = 32.0*xk*xk - 1.0;
+                let sk = xk*ps.evaluate_as_polynomial(z);
+                let ck = pc.evaluate_as_polynomial(z);
+
+                let m = (xi as i64) & 3;
+                match m
+                {
+                    0 => (sk, ck),
+                    1 => (ck, -sk),
+                    2 => (-sk, -ck),
+                    _ => (-ck, sk)
+                }
+            }
+        }
+    };
+}
+impl_approx_sin_cos!(f32; f32);
+impl_approx_sin_cos!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sin_cos!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sin_cos!(f128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn sin_cos()
+    {
+        const RANGE: f32 = 1000.0*f32::TAU;
+        let (s, c) = RANGE.approx_sin_cos();
+
+        println!("{} {}", RANGE.sin(), RANGE.cos());
+        println!("{} {}", s, c);
+        println!("sin error = {}", s - RANGE.sin());
+        println!("cos error = {}", c - RANGE.cos());
+    }
+
+    #[bench]
+    fn sin_cos_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "sin_cos",
+            [
+                &|x: F| (x.sin(), x.cos()),
+                &ApproxSinCos::approx_sin_cos
+            ],
+            -f64::TAU..f64::TAU,
+            S
+        )
+    }
+}
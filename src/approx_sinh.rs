@@ -0,0 +1,100 @@
+use super::*;
+
+use polynomial_ops::*;
+
+#[const_trait]
+pub trait ApproxSinh
+{
+    /// Calculates an approximation of `sinh(x)`.
+    ///
+    /// For `|x| < 0.5` this evaluates a short odd Maclaurin polynomial instead of
+    /// `(e^x - e^-x)/2`, since that difference of two nearly-equal [`ApproxExp::approx_exp`]
+    /// evaluations would otherwise cancel and lose relative accuracy near zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxSinh;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_sinh::<8>();
+    ///
+    /// assert!((y - x.sinh()).abs() < 0.0001);
+    /// ```
+    fn approx_sinh<const N: usize>(self) -> Self;
+}
+
+macro_rules! impl_approx_sinh {
+    ($float:ty; $consts:tt) => {
+        impl /*const*/ ApproxSinh for $float
+        {
+            fn approx_sinh<const N: usize>(self) -> Self
+            {
+                if self.abs() < 0.5
+                {
+                    let u = self*self;
+                    let mut coeffs: [$float; N] = [0.0; N];
+                    let mut term: $float = 1.0;
+                    let mut k = 0;
+                    while k < N
+                    {
+                        coeffs[k] = term;
+                        k += 1;
+                        term /= ((2*k)*(2*k + 1)) as $float;
+                    }
+                    self*coeffs.evaluate_as_polynomial(u)
+                }
+                else
+                {
+                    (self.approx_exp::<N>() - (-self).approx_exp::<N>())*0.5
+                }
+            }
+        }
+    };
+}
+impl_approx_sinh!(f32; f32);
+impl_approx_sinh!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sinh!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sinh!(f128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn sinh()
+    {
+        const X: f64 = 2.0;
+        let y = X.approx_sinh::<8>();
+
+        println!("{}", X.sinh());
+        println!("{}", y);
+        println!("error = {}", (y - X.sinh())/X.sinh());
+    }
+
+    #[bench]
+    fn sinh_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "sinh",
+            [
+                &F::sinh,
+                &ApproxSinh::approx_sinh::<4>,
+                &ApproxSinh::approx_sinh::<8>
+            ],
+            -4.0..4.0,
+            S
+        )
+    }
+}
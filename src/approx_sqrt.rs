@@ -66,6 +66,10 @@ macro_rules! impl_approx_sqrt {
 }
 impl_approx_sqrt!(f32: u32; f32);
 impl_approx_sqrt!(f64: u64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sqrt!(f16: u16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sqrt!(f128: u128; f128);
 
 #[cfg(test)]
 mod test
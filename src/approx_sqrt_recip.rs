@@ -0,0 +1,121 @@
+use super::*;
+
+#[const_trait]
+pub trait ApproxSqrtRecip
+{
+    /// Calculates an approximation of both `sqrt(x)` and `1/sqrt(x)` at once, using Goldschmidt's
+    /// coupled iteration.
+    ///
+    /// Both outputs are refined from a single shared residual per step, which makes this cheaper
+    /// than calling [`ApproxSqrt::approx_sqrt`] and [`ApproxInvSqrt::approx_inv_sqrt`] separately
+    /// when both are needed (e.g. normalizing a vector while keeping its length).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_trait_impl)]
+    ///
+    /// use float_approx_math::ApproxSqrtRecip;
+    ///
+    /// const X: f32 = 2.0;
+    /// const Y: (f32, f32) = X.approx_sqrt_rsqrt::<4>();
+    ///
+    /// assert_eq!(Y.0, X.sqrt());
+    /// assert_eq!(Y.1, X.sqrt().recip());
+    /// ```
+    fn approx_sqrt_rsqrt<const N: usize>(self) -> (Self, Self)
+    where
+        Self: Sized;
+
+    fn approx_sqrt_rsqrt_unchecked<const N: usize>(self) -> (Self, Self)
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_approx_sqrt_recip {
+    ($float:ty: $bits:ty; $consts:tt) => {
+        impl const ApproxSqrtRecip for $float
+        {
+            fn approx_sqrt_rsqrt<const N: usize>(self) -> (Self, Self)
+            {
+                if self > 0.0
+                {
+                    self.approx_sqrt_rsqrt_unchecked::<N>()
+                }
+                else
+                {
+                    (<$float>::NAN, <$float>::NAN)
+                }
+            }
+            fn approx_sqrt_rsqrt_unchecked<const N: usize>(self) -> (Self, Self)
+            {
+                const L: $bits = 1 << (<$float>::MANTISSA_DIGITS as $bits - 1);
+                const MAGIC_NUMBER: $bits = (1.5*L as f64*($consts::EXP_BIAS as f64 - SIGMA_QUAKE) + 0.5) as $bits;
+
+                let y0 = <$float>::from_bits(MAGIC_NUMBER - (<$float>::to_bits(self) >> 1));
+
+                let mut x = self*y0;
+                let mut h = 0.5*y0;
+                let mut i = 0;
+                while i < N
+                {
+                    let r = 0.5 - x*h;
+                    x += x*r;
+                    h += h*r;
+                    i += 1;
+                }
+                (x, 2.0*h)
+            }
+        }
+    };
+}
+impl_approx_sqrt_recip!(f32: u32; f32);
+impl_approx_sqrt_recip!(f64: u64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sqrt_recip!(f16: u16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_sqrt_recip!(f128: u128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn sqrt_rsqrt()
+    {
+        const X: f64 = 2.0;
+        const Y: (f64, f64) = X.approx_sqrt_rsqrt::<4>();
+
+        println!("{} {}", X.sqrt(), X.sqrt().recip());
+        println!("{} {}", Y.0, Y.1);
+        println!("sqrt error = {}", (Y.0 - X.sqrt())/X.sqrt());
+        println!("rsqrt error = {}", (Y.1 - X.sqrt().recip())/X.sqrt().recip());
+    }
+
+    #[bench]
+    fn sqrt_rsqrt_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 1000;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "sqrt_rsqrt",
+            [
+                &|x: F| (x.sqrt(), x.sqrt().recip()),
+                &ApproxSqrtRecip::approx_sqrt_rsqrt::<0>,
+                &ApproxSqrtRecip::approx_sqrt_rsqrt::<1>,
+                &ApproxSqrtRecip::approx_sqrt_rsqrt::<2>,
+                &ApproxSqrtRecip::approx_sqrt_rsqrt::<3>,
+                &ApproxSqrtRecip::approx_sqrt_rsqrt::<4>
+            ],
+            0.1..256.0,
+            S
+        );
+    }
+}
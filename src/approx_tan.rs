@@ -0,0 +1,76 @@
+use super::*;
+
+#[const_trait]
+pub trait ApproxTan
+{
+    /// Calculates an approximation of a tangent.
+    ///
+    /// This is `s/c` from [`ApproxSinCos::approx_sin_cos`], reusing its shared range
+    /// reduction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxTan;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_tan();
+    ///
+    /// assert!((y - x.tan()).abs() < 0.0001);
+    /// ```
+    fn approx_tan(self) -> Self;
+}
+
+macro_rules! impl_approx_tan {
+    ($float:ty; $consts:tt) => {
+        impl /*const*/ ApproxTan for $float
+        {
+            fn approx_tan(self) -> Self
+            {
+                let (s, c) = self.approx_sin_cos();
+                s/c
+            }
+        }
+    };
+}
+impl_approx_tan!(f32; f32);
+impl_approx_tan!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_tan!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_tan!(f128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn tan()
+    {
+        const RANGE: f32 = 1.5;
+        t::plot_approx("tan", -RANGE..RANGE, f32::tan, ApproxTan::approx_tan)
+    }
+
+    #[bench]
+    fn tan_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "tan",
+            [
+                &F::tan,
+                &ApproxTan::approx_tan
+            ],
+            -1.5..1.5,
+            S
+        )
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+#[const_trait]
+pub trait ApproxTanh
+{
+    /// Calculates an approximation of `tanh(x)`.
+    ///
+    /// This is `sinh(x)/cosh(x)` using [`ApproxSinh::approx_sinh`] and [`ApproxCosh::approx_cosh`],
+    /// which already avoids the cancellation that a direct `(e^2x - 1)/(e^2x + 1)` would suffer
+    /// near zero. Saturates to `-1.0`/`1.0` for large `|x|`, where the exponential terms would
+    /// otherwise overflow before the ratio has a chance to settle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use float_approx_math::ApproxTanh;
+    ///
+    /// let x: f32 = 2.0;
+    /// let y: f32 = x.approx_tanh::<8>();
+    ///
+    /// assert!((y - x.tanh()).abs() < 0.0001);
+    /// ```
+    fn approx_tanh<const N: usize>(self) -> Self;
+}
+
+macro_rules! impl_approx_tanh {
+    ($float:ty; $consts:tt) => {
+        impl /*const*/ ApproxTanh for $float
+        {
+            fn approx_tanh<const N: usize>(self) -> Self
+            {
+                if self > 20.0
+                {
+                    1.0
+                }
+                else if self < -20.0
+                {
+                    -1.0
+                }
+                else
+                {
+                    self.approx_sinh::<N>()/self.approx_cosh::<N>()
+                }
+            }
+        }
+    };
+}
+impl_approx_tanh!(f32; f32);
+impl_approx_tanh!(f64; f64);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_tanh!(f16; f16);
+#[cfg(feature = "unstable_float_types")]
+impl_approx_tanh!(f128; f128);
+
+#[cfg(test)]
+mod test
+{
+    use ::test::Bencher;
+
+    use super::*;
+    use crate::tests as t;
+
+    #[test]
+    fn tanh()
+    {
+        const X: f64 = 2.0;
+        let y = X.approx_tanh::<8>();
+
+        println!("{}", X.tanh());
+        println!("{}", y);
+        println!("error = {}", (y - X.tanh())/X.tanh());
+    }
+
+    #[bench]
+    fn tanh_benchmark(_: &mut Bencher)
+    {
+        type F = f64;
+
+        const N: usize = 500;
+        const S: usize = 32;
+
+        t::plot_benchmark::<_, _, N, _>(
+            "tanh",
+            [
+                &F::tanh,
+                &ApproxTanh::approx_tanh::<4>,
+                &ApproxTanh::approx_tanh::<8>
+            ],
+            -4.0..4.0,
+            S
+        )
+    }
+}
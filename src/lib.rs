@@ -11,14 +11,27 @@
 #![feature(array_zip)]
 #![feature(generic_const_exprs)]
 #![feature(generic_arg_infer)]
+#![cfg_attr(feature = "unstable_float_types", feature(f16))]
+#![cfg_attr(feature = "unstable_float_types", feature(f128))]
 
 moddef::moddef!(
     flat(pub) mod {
         approx_sqrt,
         approx_inv_sqrt,
+        approx_sqrt_recip,
 
         approx_sin,
-        approx_cos
+        approx_cos,
+        approx_sin_cos,
+
+        approx_exp,
+        approx_log,
+
+        approx_tan,
+        approx_sinh,
+        approx_cosh,
+        approx_tanh,
+        approx_sigmoid
     },
     mod {
         plot for cfg(test)
@@ -38,6 +51,21 @@ mod f64
     pub(crate) const EXP_BIAS: u64 = 1023;
 }
 
+#[cfg(feature = "unstable_float_types")]
+mod f16
+{
+    pub use core::f16::consts::*;
+
+    pub(crate) const EXP_BIAS: u16 = 15;
+}
+#[cfg(feature = "unstable_float_types")]
+mod f128
+{
+    pub use core::f128::consts::*;
+
+    pub(crate) const EXP_BIAS: u128 = 16383;
+}
+
 #[cfg(test)]
 extern crate test;
 